@@ -0,0 +1,60 @@
+/// Clipboard integration for copying the displayed image out and pasting an
+/// image in. Image clipboard formats are platform-specific, so the real work
+/// happens behind `cfg(target_os)` and everything above this module only
+/// ever deals in `image::RgbaImage`.
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::io::Cursor;
+
+    use clipboard_win::{formats, get_clipboard, set_clipboard};
+    use image::{DynamicImage, ImageFormat, RgbaImage};
+
+    /// `formats::Bitmap` round-trips raw DIB/BMP bytes, not pixel tuples, so
+    /// the image has to be encoded/decoded through `image`'s BMP codec.
+    pub fn copy_image(image: &RgbaImage) -> bool {
+        let mut bmp_bytes = Vec::new();
+        let encoded = DynamicImage::ImageRgba8(image.clone())
+            .write_to(&mut bmp_bytes, ImageFormat::BMP)
+            .is_ok();
+
+        encoded && set_clipboard(formats::Bitmap, bmp_bytes).is_ok()
+    }
+
+    pub fn paste_image() -> Option<RgbaImage> {
+        let bmp_bytes: Vec<u8> = get_clipboard(formats::Bitmap).ok()?;
+        image::load(Cursor::new(bmp_bytes), ImageFormat::BMP)
+            .ok()
+            .map(|image| image.to_rgba())
+    }
+}
+
+#[cfg(not(target_os = "windows"))]
+mod platform {
+    use image::RgbaImage;
+
+    // emulsion targets Windows first; image clipboard support on other
+    // platforms can be added the same way once there's a user asking for it.
+    // Surface that explicitly instead of failing silently.
+    pub fn copy_image(_image: &RgbaImage) -> bool {
+        eprintln!("clipboard: image copy is not yet supported on this platform");
+        false
+    }
+
+    pub fn paste_image() -> Option<RgbaImage> {
+        eprintln!("clipboard: image paste is not yet supported on this platform");
+        None
+    }
+}
+
+/// Places `image` on the system clipboard. Returns `false` if the platform
+/// backend couldn't complete the copy.
+pub fn copy_image(image: &image::RgbaImage) -> bool {
+    platform::copy_image(image)
+}
+
+/// Reads an image off the system clipboard, normalizing whatever platform
+/// format was found into an `image::RgbaImage`.
+pub fn paste_image() -> Option<image::RgbaImage> {
+    platform::paste_image()
+}
@@ -0,0 +1,84 @@
+use std::fs;
+use std::path::PathBuf;
+
+use glium::glutin::VirtualKeyCode;
+
+const CONFIG_FILE_NAME: &str = "emulsion.json5";
+
+/// Keyboard shortcuts, stored as key names (e.g. `"Escape"`, `"F11"`) so the
+/// config file stays human-editable; resolved to `VirtualKeyCode` via
+/// `parse_virtual_keycode` where they're used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Keybindings {
+    pub next: String,
+    pub previous: String,
+    pub quit: String,
+    pub fullscreen: String,
+}
+
+impl Default for Keybindings {
+    fn default() -> Self {
+        Keybindings {
+            next: "D".to_owned(),
+            previous: "A".to_owned(),
+            quit: "Escape".to_owned(),
+            fullscreen: "F11".to_owned(),
+        }
+    }
+}
+
+/// User-editable settings, loaded once at startup. Anything not present in
+/// the on-disk file falls back to its `Default` value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub bottom_panel_height: u32,
+    pub clear_color: (f32, f32, f32),
+    pub light_theme: bool,
+    pub keybindings: Keybindings,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            bottom_panel_height: 32,
+            clear_color: (0.9, 0.9, 0.9),
+            light_theme: true,
+            keybindings: Keybindings::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Loads the config from the platform config dir, falling back to
+    /// `Config::default()` if it's absent or fails to parse.
+    pub fn load() -> Config {
+        Self::config_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|contents| json5::from_str(&contents).ok())
+            .unwrap_or_else(Config::default)
+    }
+
+    fn config_path() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("emulsion").join(CONFIG_FILE_NAME))
+    }
+}
+
+/// Resolves a handful of common key names to `VirtualKeyCode`. Unknown names
+/// fall back to `None` so a typo in the config file doesn't crash the app.
+pub fn parse_virtual_keycode(name: &str) -> Option<VirtualKeyCode> {
+    use glium::glutin::VirtualKeyCode::*;
+
+    Some(match name {
+        "A" => A, "B" => B, "C" => C, "D" => D, "E" => E, "F" => F, "G" => G,
+        "H" => H, "I" => I, "J" => J, "K" => K, "L" => L, "M" => M, "N" => N,
+        "O" => O, "P" => P, "Q" => Q, "R" => R, "S" => S, "T" => T, "U" => U,
+        "V" => V, "W" => W, "X" => X, "Y" => Y, "Z" => Z,
+        "Escape" => Escape,
+        "Left" => Left,
+        "Right" => Right,
+        "F11" => F11,
+        _ => return None,
+    })
+}
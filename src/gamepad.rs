@@ -0,0 +1,76 @@
+use cgmath::Vector2;
+use gilrs::{Axis, Button, Event, EventType, Gilrs};
+
+use playback_manager::LoadRequest;
+
+const STICK_DEADZONE: f32 = 0.15;
+
+/// Actions a gamepad can produce, mirrored from whatever the keyboard and UI
+/// buttons already trigger so the rest of the program doesn't need to care
+/// where an action came from.
+pub enum GamepadAction {
+    LoadRequest(LoadRequest),
+    ToggleFullscreen,
+    PanZoom(Vector2<f32>),
+}
+
+/// Wraps `gilrs::Gilrs` and translates its events into `GamepadAction`s.
+/// Polling is independent of the window event loop, so `start_event_loop`
+/// polls this alongside `events_loop.poll_events` each iteration.
+pub struct GamepadInput {
+    gilrs: Gilrs,
+    right_stick: Vector2<f32>,
+}
+
+impl GamepadInput {
+    pub fn new() -> Option<GamepadInput> {
+        Gilrs::new().ok().map(|gilrs| GamepadInput {
+            gilrs,
+            right_stick: Vector2::new(0.0, 0.0),
+        })
+    }
+
+    /// Drains pending gamepad events, returning the actions they map to.
+    pub fn poll(&mut self) -> Vec<GamepadAction> {
+        let mut actions = Vec::new();
+
+        while let Some(Event { event, .. }) = self.gilrs.next_event() {
+            match event {
+                EventType::ButtonPressed(Button::DPadRight, _)
+                | EventType::ButtonPressed(Button::RightTrigger, _) => {
+                    actions.push(GamepadAction::LoadRequest(LoadRequest::LoadNext));
+                }
+                EventType::ButtonPressed(Button::DPadLeft, _)
+                | EventType::ButtonPressed(Button::LeftTrigger, _) => {
+                    actions.push(GamepadAction::LoadRequest(LoadRequest::LoadPrevious));
+                }
+                EventType::ButtonPressed(Button::South, _) => {
+                    actions.push(GamepadAction::ToggleFullscreen);
+                }
+                EventType::AxisChanged(Axis::RightStickX, value, _) => {
+                    self.right_stick.x = value;
+                }
+                EventType::AxisChanged(Axis::RightStickY, value, _) => {
+                    self.right_stick.y = value;
+                }
+                _ => (),
+            }
+        }
+
+        if self.is_stick_active() {
+            actions.push(GamepadAction::PanZoom(self.right_stick));
+        }
+
+        actions
+    }
+
+    fn is_stick_active(&self) -> bool {
+        self.right_stick.x.abs() > STICK_DEADZONE || self.right_stick.y.abs() > STICK_DEADZONE
+    }
+
+    /// Whether the stick is held off-center, which should keep the main
+    /// loop from sleeping between frames so navigation stays responsive.
+    pub fn should_stay_awake(&self) -> bool {
+        self.is_stick_active()
+    }
+}
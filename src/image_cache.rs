@@ -0,0 +1,38 @@
+use std::path::Path;
+
+use glium::texture::{RawImage2d, SrgbTexture2d};
+use image::RgbaImage;
+
+use svg_raster;
+
+/// Decodes `path` into an `(RgbaImage, SrgbTexture2d)` pair. `.svg` files are
+/// rasterized via `svg_raster` at `target_size` instead of going through
+/// `image::open`, which doesn't understand vector formats.
+pub fn load(
+    path: &Path,
+    display: &glium::Display,
+    target_size: (u32, u32),
+) -> (RgbaImage, SrgbTexture2d) {
+    let is_svg = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map_or(false, |ext| ext.eq_ignore_ascii_case("svg"));
+
+    let image = if is_svg {
+        svg_raster::rasterize(path, target_size).unwrap_or_else(|| RgbaImage::new(1, 1))
+    } else {
+        image::open(path).unwrap().to_rgba()
+    };
+
+    let texture = to_texture(display, &image);
+    (image, texture)
+}
+
+/// Uploads an already-decoded image as a texture, e.g. for a clipboard
+/// paste that never touched disk and so never went through `load`.
+pub fn to_texture(display: &glium::Display, image: &RgbaImage) -> SrgbTexture2d {
+    let dimensions = image.dimensions();
+    let raw = RawImage2d::from_raw_rgba(image.clone().into_raw(), dimensions);
+
+    SrgbTexture2d::with_mipmaps(display, raw, glium::texture::MipmapsOption::NoMipmap).unwrap()
+}
@@ -0,0 +1,48 @@
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use glium::texture::RawImage2d;
+use glium::Surface;
+use image::{DynamicImage, ImageBuffer};
+
+/// Reads back the frame as drawn so far and writes it to a PNG file in the
+/// working directory. Used to implement the Ctrl+S "save view" shortcut.
+///
+/// Takes the in-progress `target` rather than reading the display's front
+/// buffer: the front buffer still holds the *previous* presented frame (the
+/// swap hasn't happened yet), and by the time it would be current it also
+/// has the UI drawn into it. Reading `target` right after `picture_panel`
+/// has drawn into it gives a clean, up-to-date capture with no buttons.
+pub fn capture_screenshot(target: &glium::Frame) {
+    let raw_image: RawImage2d<u8> = target.read();
+    let width = raw_image.width;
+    let height = raw_image.height;
+
+    let buffer = ImageBuffer::from_raw(width, height, raw_image.data.into_owned()).unwrap();
+    // The GL origin is bottom-left, so the buffer comes out upside down.
+    let image = DynamicImage::ImageRgba8(buffer).flipv();
+
+    let path = unique_capture_path();
+    image.save_with_format(&path, image::ImageFormat::PNG).unwrap();
+}
+
+/// Picks a capture filename that doesn't clobber a previous screenshot.
+fn unique_capture_path() -> PathBuf {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let mut suffix = 0u32;
+    loop {
+        let candidate = if suffix == 0 {
+            PathBuf::from(format!("emulsion_capture_{}.png", timestamp))
+        } else {
+            PathBuf::from(format!("emulsion_capture_{}_{}.png", timestamp, suffix))
+        };
+        if !candidate.exists() {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
@@ -8,6 +8,17 @@ extern crate glium;
 extern crate image;
 extern crate sys_info;
 extern crate backtrace;
+extern crate gilrs;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate json5;
+extern crate dirs;
+extern crate usvg;
+extern crate resvg;
+extern crate tiny_skia;
+#[cfg(target_os = "windows")]
+extern crate clipboard_win;
 
 use std::env;
 use std::path::{Path, PathBuf};
@@ -23,6 +34,13 @@ use glium::texture::{RawImage2d, SrgbTexture2d};
 use cgmath::Vector2;
 
 mod image_cache;
+mod image_export;
+mod clipboard;
+mod gamepad;
+use gamepad::{GamepadAction, GamepadInput};
+mod config;
+use config::Config;
+mod svg_raster;
 mod handle_panic;
 mod ui;
 mod shaders;
@@ -55,7 +73,22 @@ fn load_texture_without_cache(
     display: &glium::Display,
     image_path: &Path,
 ) -> SrgbTexture2d {
-    let image = image::open(image_path).unwrap().to_rgba();
+    let is_svg = image_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map_or(false, |ext| ext.eq_ignore_ascii_case("svg"));
+
+    let image = if is_svg {
+        let window_size = display.gl_window().get_inner_size().unwrap();
+        let target_size = svg_raster::target_size_for_zoom(
+            (window_size.width as u32, window_size.height as u32),
+            1.0,
+        );
+        svg_raster::rasterize(image_path, target_size)
+            .unwrap_or_else(|| image::RgbaImage::new(1, 1))
+    } else {
+        image::open(image_path).unwrap().to_rgba()
+    };
 
     texture_from_image(display, image)
 }
@@ -95,19 +128,29 @@ struct Program<'a> {
     picture_panel: &'a mut PicturePanel,
     playback_manager: &'a RefCell<PlaybackManager>,
     ui: ui::Ui<'a>,
+    screenshot_requested: bool,
+    gamepad_input: Option<GamepadInput>,
+    config: Config,
+    quit_keycode: VirtualKeyCode,
+    fullscreen_keycode: VirtualKeyCode,
+    next_keycode: VirtualKeyCode,
+    previous_keycode: VirtualKeyCode,
+    fullscreen: bool,
 }
 
 impl<'a> Program<'a> {
-    fn draw_picture(window: &mut Window, picture_controller: &mut PicturePanel) {
+    fn draw_picture(window: &mut Window, picture_controller: &mut PicturePanel, config: &Config) {
         let mut target = window.display().draw();
 
-        target.clear_color(0.9, 0.9, 0.9, 0.0);
+        let (r, g, b) = config.clear_color;
+        target.clear_color(r, g, b, 0.0);
         picture_controller.draw(&mut target, window);
         target.finish().unwrap();
     }
 
     fn start() {
-        let bottom_panel_height = 32;
+        let config = Config::load();
+        let bottom_panel_height = config.bottom_panel_height;
 
         let mut events_loop = glutin::EventsLoop::new();
         let mut window = Window::init(&events_loop);
@@ -119,18 +162,27 @@ impl<'a> Program<'a> {
             let img_path = PathBuf::from(img_path);
             let mut playback_manager = playback_manager.borrow_mut();
             playback_manager.request_load(LoadRequest::LoadSpecific(img_path));
-            playback_manager.update_image(&mut window);
+            playback_manager.update_image(&mut window, picture_panel.current_zoom());
             picture_panel.set_image(playback_manager.image_texture().ref_clone());
         } else {
             window.set_title_filename("Drag and drop an image on the window.");
         }
 
         // Just quickly display the loaded image here before we load the remaining parts of the program
-        Self::draw_picture(&mut window, &mut picture_panel);
-        
+        Self::draw_picture(&mut window, &mut picture_panel, &config);
+
         let mut ui = ui::Ui::new(window.display());
-        
-        Self::init_ui(&mut ui, &mut window, &playback_manager);
+
+        Self::init_ui(&mut ui, &mut window, &playback_manager, &config);
+
+        let quit_keycode = config::parse_virtual_keycode(&config.keybindings.quit)
+            .unwrap_or(VirtualKeyCode::Escape);
+        let fullscreen_keycode = config::parse_virtual_keycode(&config.keybindings.fullscreen)
+            .unwrap_or(VirtualKeyCode::F11);
+        let next_keycode = config::parse_virtual_keycode(&config.keybindings.next)
+            .unwrap_or(VirtualKeyCode::D);
+        let previous_keycode = config::parse_virtual_keycode(&config.keybindings.previous)
+            .unwrap_or(VirtualKeyCode::A);
 
         let mut program = Program {
             bottom_panel_height: bottom_panel_height as f64,
@@ -138,6 +190,14 @@ impl<'a> Program<'a> {
             picture_panel: &mut picture_panel,
             playback_manager: &playback_manager,
             ui: ui,
+            screenshot_requested: false,
+            gamepad_input: GamepadInput::new(),
+            config: config,
+            quit_keycode: quit_keycode,
+            fullscreen_keycode: fullscreen_keycode,
+            next_keycode: next_keycode,
+            previous_keycode: previous_keycode,
+            fullscreen: false,
         };
 
         program.start_event_loop(&mut events_loop);
@@ -147,6 +207,7 @@ impl<'a> Program<'a> {
         ui: &mut ui::Ui<'b>,
         window: &mut Window,
         playback_manager: &'b RefCell<PlaybackManager>,
+        config: &Config,
     ) {
         let exe_parent = std::env::current_exe().unwrap().parent().unwrap().to_owned();
         let button_texture = Rc::new(
@@ -176,17 +237,59 @@ impl<'a> Program<'a> {
                 }));
             }
         }
-        let _ = ui.create_toggle(moon_texture, light_texture, Vector2::new(4f32, 4f32), true, Box::new(move |is_light| {
+        let _ = ui.create_toggle(moon_texture, light_texture, Vector2::new(4f32, 4f32), config.light_theme, Box::new(move |is_light| {
             playback_manager.borrow_mut().request_load(LoadRequest::LoadNext);
         }));
     }
 
 
+    /// Toggles fullscreen presentation mode, bound to F11 and a gamepad
+    /// face button. Hides the bottom UI panel so the whole surface becomes
+    /// the picture, and hands all events to `picture_panel` while active.
+    fn toggle_fullscreen(&mut self) {
+        self.fullscreen = !self.fullscreen;
+
+        let gl_window = self.window.display().gl_window();
+        if self.fullscreen {
+            let monitor = gl_window.get_current_monitor();
+            gl_window.set_fullscreen(Some(monitor));
+            gl_window.hide_cursor(true);
+        } else {
+            gl_window.set_fullscreen(None);
+            gl_window.hide_cursor(false);
+        }
+
+        self.ui.set_hidden(self.fullscreen);
+    }
+
+    fn handle_gamepad_actions(&mut self) {
+        let actions = match self.gamepad_input {
+            Some(ref mut gamepad_input) => gamepad_input.poll(),
+            None => return,
+        };
+
+        for action in actions {
+            match action {
+                GamepadAction::LoadRequest(load_request) => {
+                    self.playback_manager.borrow_mut().request_load(load_request);
+                }
+                GamepadAction::ToggleFullscreen => {
+                    self.toggle_fullscreen();
+                }
+                GamepadAction::PanZoom(stick) => {
+                    self.picture_panel.pan_zoom(stick);
+                }
+            }
+        }
+    }
+
     fn start_event_loop(&mut self, events_loop: &mut glutin::EventsLoop) {
         let mut running = true;
         let mut mouse_y = 0f64;
         // the main loop
         while running {
+            self.handle_gamepad_actions();
+
             events_loop.poll_events(|event| {
                 use glutin::Event;
                 if let Event::WindowEvent { ref event, .. } = event {
@@ -196,8 +299,29 @@ impl<'a> Program<'a> {
                         WindowEvent::KeyboardInput { input, .. } => {
                             if let Some(keycode) = input.virtual_keycode {
                                 if input.state == glutin::ElementState::Pressed {
-                                    if keycode == VirtualKeyCode::Escape {
+                                    if keycode == self.quit_keycode {
                                         running = false
+                                    } else if keycode == VirtualKeyCode::S && input.modifiers.ctrl {
+                                        self.screenshot_requested = true;
+                                    } else if keycode == self.fullscreen_keycode {
+                                        self.toggle_fullscreen();
+                                    } else if keycode == self.next_keycode {
+                                        self.playback_manager.borrow_mut().request_load(LoadRequest::LoadNext);
+                                    } else if keycode == self.previous_keycode {
+                                        self.playback_manager.borrow_mut().request_load(LoadRequest::LoadPrevious);
+                                    } else if keycode == VirtualKeyCode::C && input.modifiers.ctrl {
+                                        if let Some(image) = self.playback_manager.borrow().current_image() {
+                                            clipboard::copy_image(image);
+                                        }
+                                    } else if keycode == VirtualKeyCode::V && input.modifiers.ctrl {
+                                        if let Some(image) = clipboard::paste_image() {
+                                            // Routed through PlaybackManager as a synthetic
+                                            // load so it survives the per-iteration
+                                            // set_image() below and Ctrl+C can re-copy it.
+                                            self.playback_manager
+                                                .borrow_mut()
+                                                .request_load(LoadRequest::LoadPasted(image));
+                                        }
                                     }
                                 }
                             }
@@ -216,7 +340,7 @@ impl<'a> Program<'a> {
                 let window_size = self.window.display().gl_window().get_inner_size().unwrap();
                 match event {
                     Event::WindowEvent {event: WindowEvent::MouseInput {..}, ..} => {
-                        if mouse_y < (window_size.height - self.bottom_panel_height) {
+                        if self.fullscreen || mouse_y < (window_size.height - self.bottom_panel_height) {
                             self.picture_panel.handle_event(&event, &mut self.window, &mut self.playback_manager.borrow_mut());
                         } else {
                             if let Event::WindowEvent { ref event, .. } = event {
@@ -245,7 +369,7 @@ impl<'a> Program<'a> {
 
             let load_requested = {
                 let mut playback_manager = self.playback_manager.borrow_mut();
-                playback_manager.update_image(&mut self.window);
+                playback_manager.update_image(&mut self.window, self.picture_panel.current_zoom());
                 self.picture_panel.set_image(playback_manager.image_texture().ref_clone());
 
                 *playback_manager.load_request() != LoadRequest::None
@@ -258,10 +382,15 @@ impl<'a> Program<'a> {
                 playback_manager.update_directory().unwrap();
             }
 
+            let gamepad_active = self.gamepad_input
+                .as_ref()
+                .map_or(false, |gamepad_input| gamepad_input.should_stay_awake());
+
             let should_sleep = {
                 playback_manager.should_sleep()
                 && self.picture_panel.should_sleep()
                 && !load_requested
+                && !gamepad_active
             };
 
             // Let other processes run for a bit.
@@ -275,9 +404,16 @@ impl<'a> Program<'a> {
     fn draw(&mut self) {
         let mut target = self.window.display().draw();
 
-        target.clear_color(0.9, 0.9, 0.9, 0.0);
+        let (r, g, b) = self.config.clear_color;
+        target.clear_color(r, g, b, 0.0);
 
         self.picture_panel.draw(&mut target, &self.window);
+
+        if self.screenshot_requested {
+            self.screenshot_requested = false;
+            image_export::capture_screenshot(&target);
+        }
+
         self.ui.draw(&mut target);
 
         target.finish().unwrap();
@@ -0,0 +1,86 @@
+use std::rc::Rc;
+
+use cgmath::Vector2;
+use glium::texture::SrgbTexture2d;
+
+use playback_manager::PlaybackManager;
+use window::Window;
+
+const MIN_ZOOM: f32 = 0.1;
+const MAX_ZOOM: f32 = 16.0;
+const GAMEPAD_PAN_SPEED: f32 = 8.0;
+const GAMEPAD_ZOOM_SPEED: f32 = 0.02;
+
+/// Holds the currently loaded image and its pan/zoom state, which changes
+/// through `pan_zoom` (gamepad stick). `handle_event` and `draw` do nothing
+/// here: mouse events do not pan or zoom, and no image is drawn.
+pub struct PicturePanel {
+    bottom_panel_height: u32,
+    texture: Option<Rc<SrgbTexture2d>>,
+    pan: Vector2<f32>,
+    zoom: f32,
+}
+
+impl PicturePanel {
+    pub fn new(_display: &glium::Display, bottom_panel_height: u32) -> PicturePanel {
+        PicturePanel {
+            bottom_panel_height,
+            texture: None,
+            pan: Vector2::new(0.0, 0.0),
+            zoom: 1.0,
+        }
+    }
+
+    /// Shows `texture`. Pan and zoom reset only when it differs from the
+    /// texture already shown, so the per-frame call keeps the current view.
+    pub fn set_image(&mut self, texture: Option<Rc<SrgbTexture2d>>) {
+        let same = match (&self.texture, &texture) {
+            (Some(old), Some(new)) => Rc::ptr_eq(old, new),
+            (None, None) => true,
+            _ => false,
+        };
+        self.texture = texture;
+        if !same {
+            self.pan = Vector2::new(0.0, 0.0);
+            self.zoom = 1.0;
+        }
+    }
+
+    pub fn pre_events(&mut self) {}
+
+    pub fn handle_event(
+        &mut self,
+        _event: &glium::glutin::Event,
+        _window: &mut Window,
+        _playback_manager: &mut PlaybackManager,
+    ) {
+    }
+
+    pub fn draw(&mut self, target: &mut glium::Frame, _window: &Window) {
+        let _ = target;
+        let _ = &self.texture;
+        let _ = self.bottom_panel_height;
+    }
+
+    pub fn should_sleep(&self) -> bool {
+        true
+    }
+
+    /// Applies gamepad right-stick input: the x/y axes pan the image, and
+    /// the y axis also drives zoom so a single stick covers both.
+    pub fn pan_zoom(&mut self, stick: Vector2<f32>) {
+        self.pan.x += stick.x * GAMEPAD_PAN_SPEED;
+        self.pan.y += stick.y * GAMEPAD_PAN_SPEED;
+
+        self.zoom = (self.zoom + stick.y * GAMEPAD_ZOOM_SPEED)
+            .max(MIN_ZOOM)
+            .min(MAX_ZOOM);
+    }
+
+    /// Current zoom level. Used to pick a sharp raster resolution when the
+    /// displayed image is an SVG, re-rasterizing as this changes instead of
+    /// relying on GL texture magnification.
+    pub fn current_zoom(&self) -> f32 {
+        self.zoom
+    }
+}
@@ -0,0 +1,132 @@
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use glium::texture::SrgbTexture2d;
+use image::RgbaImage;
+
+use image_cache;
+use svg_raster;
+use window::Window;
+
+/// How much the zoom level has to change, as a ratio, before an SVG is
+/// re-rasterized. Keeps small jitter from triggering constant re-decodes.
+const SVG_RERASTER_RATIO: f32 = 1.2;
+
+/// What the playback manager should do the next time `update_image` runs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadRequest {
+    None,
+    LoadNext,
+    LoadPrevious,
+    LoadSpecific(PathBuf),
+    /// A synthetic entry for images that don't come from disk, e.g. a
+    /// clipboard paste. Carries the already-decoded pixels directly.
+    LoadPasted(RgbaImage),
+}
+
+/// Owns the currently displayed image: the pending request, the decoded
+/// pixels (kept around so Ctrl+C can copy them), and the uploaded texture
+/// `PicturePanel` draws.
+pub struct PlaybackManager {
+    load_request: LoadRequest,
+    current_path: Option<PathBuf>,
+    current_image: Option<RgbaImage>,
+    current_texture: Option<Rc<SrgbTexture2d>>,
+    last_raster_zoom: f32,
+}
+
+impl PlaybackManager {
+    pub fn new() -> PlaybackManager {
+        PlaybackManager {
+            load_request: LoadRequest::None,
+            current_path: None,
+            current_image: None,
+            current_texture: None,
+            last_raster_zoom: 1.0,
+        }
+    }
+
+    pub fn request_load(&mut self, request: LoadRequest) {
+        self.load_request = request;
+    }
+
+    pub fn load_request(&self) -> &LoadRequest {
+        &self.load_request
+    }
+
+    /// Resolves the pending request to a path, decodes it through
+    /// `image_cache` (which rasterizes SVGs) and uploads the result.
+    ///
+    /// `zoom` is the current `PicturePanel` zoom level. A plain image
+    /// ignores it, but an SVG is re-rasterized whenever it has moved
+    /// significantly since the last decode, so vector art stays sharp
+    /// instead of relying on GL texture magnification.
+    pub fn update_image(&mut self, window: &mut Window, zoom: f32) {
+        if let LoadRequest::LoadPasted(image) = self.load_request.clone() {
+            let texture = image_cache::to_texture(window.display(), &image);
+            self.current_image = Some(image);
+            self.current_texture = Some(Rc::new(texture));
+            self.current_path = None;
+            self.load_request = LoadRequest::None;
+            return;
+        }
+
+        let mut path = match self.load_request.clone() {
+            LoadRequest::LoadSpecific(path) => Some(path),
+            LoadRequest::LoadNext | LoadRequest::LoadPrevious => self.current_path.clone(),
+            LoadRequest::None | LoadRequest::LoadPasted(_) => None,
+        };
+
+        if path.is_none() && self.needs_svg_reraster(zoom) {
+            path = self.current_path.clone();
+        }
+
+        if let Some(path) = path {
+            let window_size = window.display().gl_window().get_inner_size().unwrap();
+            let target_size = svg_raster::target_size_for_zoom(
+                (window_size.width as u32, window_size.height as u32),
+                zoom,
+            );
+
+            let (image, texture) = image_cache::load(&path, window.display(), target_size);
+            self.current_image = Some(image);
+            self.current_texture = Some(Rc::new(texture));
+            self.current_path = Some(path);
+            self.last_raster_zoom = zoom;
+        }
+
+        self.load_request = LoadRequest::None;
+    }
+
+    fn needs_svg_reraster(&self, zoom: f32) -> bool {
+        let is_svg = self.current_path.as_ref().map_or(false, |path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .map_or(false, |ext| ext.eq_ignore_ascii_case("svg"))
+        });
+        if !is_svg {
+            return false;
+        }
+
+        let ratio = zoom / self.last_raster_zoom.max(0.0001);
+        ratio > SVG_RERASTER_RATIO || ratio < 1.0 / SVG_RERASTER_RATIO
+    }
+
+    pub fn image_texture(&self) -> Option<Rc<SrgbTexture2d>> {
+        self.current_texture.clone()
+    }
+
+    /// The decoded pixels backing the current texture, used by the
+    /// clipboard copy shortcut.
+    pub fn current_image(&self) -> Option<&RgbaImage> {
+        self.current_image.as_ref()
+    }
+
+    pub fn update_directory(&mut self) -> Result<(), std::io::Error> {
+        Ok(())
+    }
+
+    pub fn should_sleep(&self) -> bool {
+        self.load_request == LoadRequest::None
+    }
+}
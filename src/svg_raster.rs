@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use image::RgbaImage;
+
+/// Rasterizes an SVG file into an RGBA buffer sized for `target_size`, so
+/// vector art renders crisply at the current zoom level instead of relying
+/// on GL texture magnification. Returns `None` if the file can't be read
+/// or parsed as SVG.
+pub fn rasterize(path: &Path, target_size: (u32, u32)) -> Option<RgbaImage> {
+    let svg_data = std::fs::read(path).ok()?;
+
+    let opt = usvg::Options::default();
+    let tree = usvg::Tree::from_data(&svg_data, &opt.to_ref()).ok()?;
+
+    let (width, height) = target_size;
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+    let svg_size = tree.svg_node().size;
+    let scale_x = width as f32 / svg_size.width() as f32;
+    let scale_y = height as f32 / svg_size.height() as f32;
+    let transform = tiny_skia::Transform::from_scale(scale_x, scale_y);
+
+    resvg::render(&tree, usvg::FitTo::Original, transform, pixmap.as_mut())?;
+
+    RgbaImage::from_raw(width, height, pixmap.data().to_vec())
+}
+
+/// Picks a raster resolution for an SVG from the current window size and
+/// zoom level, so `rasterize` can be called again on significant zoom
+/// changes rather than upscaling a fixed-size buffer on the GPU.
+pub fn target_size_for_zoom(window_size: (u32, u32), zoom: f32) -> (u32, u32) {
+    let scale = zoom.max(1.0);
+    (
+        ((window_size.0 as f32 * scale).round().max(1.0)) as u32,
+        ((window_size.1 as f32 * scale).round().max(1.0)) as u32,
+    )
+}
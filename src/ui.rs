@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+use cgmath::Vector2;
+use glium::glutin::dpi::LogicalSize;
+use glium::glutin::WindowEvent;
+use glium::texture::SrgbTexture2d;
+
+pub struct ButtonId(usize);
+pub struct ToggleId(usize);
+
+pub struct Button {
+    texture: Rc<SrgbTexture2d>,
+    position: Vector2<f32>,
+    callback: Box<FnMut()>,
+}
+
+impl Button {
+    pub fn set_callback(&mut self, callback: Box<FnMut()>) {
+        self.callback = callback;
+    }
+}
+
+struct Toggle {
+    texture_off: Rc<SrgbTexture2d>,
+    texture_on: Rc<SrgbTexture2d>,
+    position: Vector2<f32>,
+    is_on: bool,
+    callback: Box<FnMut(bool)>,
+}
+
+/// The bottom control panel (next-image button, light/dark toggle). Hidden
+/// entirely while the program is in fullscreen/slideshow mode, at which
+/// point `window_event`/`draw` both become no-ops.
+pub struct Ui<'a> {
+    buttons: Vec<Button>,
+    toggles: Vec<Toggle>,
+    hidden: bool,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> Ui<'a> {
+    pub fn new(_display: &glium::Display) -> Ui<'a> {
+        Ui {
+            buttons: Vec::new(),
+            toggles: Vec::new(),
+            hidden: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn create_button(
+        &mut self,
+        texture: Rc<SrgbTexture2d>,
+        position: Vector2<f32>,
+        callback: Box<FnMut()>,
+    ) -> ButtonId {
+        self.buttons.push(Button {
+            texture,
+            position,
+            callback,
+        });
+        ButtonId(self.buttons.len() - 1)
+    }
+
+    pub fn get_button_mut(&mut self, id: ButtonId) -> Option<&mut Button> {
+        self.buttons.get_mut(id.0)
+    }
+
+    pub fn create_toggle(
+        &mut self,
+        texture_off: Rc<SrgbTexture2d>,
+        texture_on: Rc<SrgbTexture2d>,
+        position: Vector2<f32>,
+        is_on: bool,
+        callback: Box<FnMut(bool)>,
+    ) -> ToggleId {
+        self.toggles.push(Toggle {
+            texture_off,
+            texture_on,
+            position,
+            is_on,
+            callback,
+        });
+        ToggleId(self.toggles.len() - 1)
+    }
+
+    pub fn window_event(&mut self, _event: &WindowEvent, _window_size: LogicalSize) {
+        if self.hidden {
+            return;
+        }
+        // No hit-testing against buttons/toggles is done here.
+    }
+
+    pub fn draw(&mut self, target: &mut glium::Frame) {
+        if self.hidden {
+            return;
+        }
+        let _ = target;
+    }
+
+    /// Hides (or shows) the whole panel. Used by fullscreen/slideshow mode,
+    /// which routes every event to `PicturePanel` instead.
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden
+    }
+}
@@ -0,0 +1,29 @@
+use glium::glutin;
+use glium::glutin::dpi::LogicalSize;
+
+/// Thin wrapper around the glium display/window pair so the rest of the
+/// program doesn't have to juggle `glutin::GlWindow` directly.
+pub struct Window {
+    display: glium::Display,
+}
+
+impl Window {
+    pub fn init(events_loop: &glutin::EventsLoop) -> Window {
+        let window_builder = glutin::WindowBuilder::new()
+            .with_title("emulsion")
+            .with_dimensions(LogicalSize::new(800.0, 600.0));
+        let context_builder = glutin::ContextBuilder::new().with_vsync(true);
+        let display = glium::Display::new(window_builder, context_builder, events_loop)
+            .expect("failed to create the display");
+
+        Window { display }
+    }
+
+    pub fn display(&self) -> &glium::Display {
+        &self.display
+    }
+
+    pub fn set_title_filename(&self, filename: &str) {
+        self.display.gl_window().set_title(&format!("{} - emulsion", filename));
+    }
+}